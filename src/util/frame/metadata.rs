@@ -0,0 +1,77 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use DictionaryRef;
+
+/** Typed accessors for the well-known metadata keys FFmpeg attaches to a
+ * `Frame`'s dictionary: `creation_time`, `language`, and `rotate`.
+ * Keys that are missing or fail to parse become `None` rather than
+ * erroring. */
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct MediaMetadata {
+    pub creation_time: Option<DateTime<Utc>>,
+    pub language: Option<String>,
+    pub rotation: Option<i32>,
+}
+
+impl MediaMetadata {
+    pub(crate) fn parse(dict: DictionaryRef) -> Self {
+        MediaMetadata {
+            creation_time: dict.get("creation_time").and_then(parse_creation_time),
+            language: dict.get("language").map(ToOwned::to_owned),
+            rotation: dict.get("rotate").and_then(|value| value.parse().ok()),
+        }
+    }
+}
+
+/** Parse an ISO-8601/RFC-3339 `creation_time` value such as
+ * `2023-01-27T21:25:51.000000Z`, its offset-less form
+ * `2023-01-27T21:25:51.000000`, or the `%Y-%m-%d %H:%M:%S` form some
+ * muxers emit instead. */
+fn parse_creation_time(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    if let Ok(naive) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(naive.and_utc());
+    }
+
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn parses_rfc3339_with_trailing_z() {
+        assert_eq!(
+            parse_creation_time("2023-01-27T21:25:51.000000Z"),
+            Some(Utc.with_ymd_and_hms(2023, 1, 27, 21, 25, 51).unwrap())
+        );
+    }
+
+    #[test]
+    fn parses_rfc3339_without_offset() {
+        assert_eq!(
+            parse_creation_time("2023-01-27T21:25:51.000000"),
+            Some(Utc.with_ymd_and_hms(2023, 1, 27, 21, 25, 51).unwrap())
+        );
+    }
+
+    #[test]
+    fn parses_space_separated_fallback() {
+        assert_eq!(
+            parse_creation_time("2023-01-27 21:25:51"),
+            Some(Utc.with_ymd_and_hms(2023, 1, 27, 21, 25, 51).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(parse_creation_time("not a date"), None);
+    }
+}
@@ -10,9 +10,12 @@ pub use self::audio::Audio;
 pub mod flag;
 pub use self::flag::Flags;
 
+pub mod metadata;
+pub use self::metadata::MediaMetadata;
+
 use ffi::*;
 use libc::c_int;
-use {Dictionary, DictionaryRef};
+use {Dictionary, DictionaryRef, Error};
 
 /** Packet data from a `Frame`. */
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
@@ -21,6 +24,7 @@ pub struct Packet {
     pub position: i64,
     pub size: usize,
 
+    #[cfg(not(feature = "ffmpeg_5_0"))]
     pub pts: i64,
     pub dts: i64,
 }
@@ -87,10 +91,22 @@ impl Frame {
     pub fn packet(&self) -> Packet {
         unsafe {
             Packet {
+                #[cfg(not(feature = "ffmpeg_5_0"))]
                 duration: av_frame_get_pkt_duration(self.as_ptr()) as i64,
+                #[cfg(feature = "ffmpeg_5_0")]
+                duration: (*self.as_ptr()).pkt_duration as i64,
+
+                #[cfg(not(feature = "ffmpeg_5_0"))]
                 position: av_frame_get_pkt_pos(self.as_ptr()) as i64,
+                #[cfg(feature = "ffmpeg_5_0")]
+                position: (*self.as_ptr()).pkt_pos as i64,
+
+                #[cfg(not(feature = "ffmpeg_5_0"))]
                 size: av_frame_get_pkt_size(self.as_ptr()) as usize,
+                #[cfg(feature = "ffmpeg_5_0")]
+                size: (*self.as_ptr()).pkt_size as usize,
 
+                #[cfg(not(feature = "ffmpeg_5_0"))]
                 pts: (*self.as_ptr()).pkt_pts,
                 dts: (*self.as_ptr()).pkt_dts,
             }
@@ -121,7 +137,12 @@ impl Frame {
     #[inline]
     pub fn timestamp(&self) -> Option<i64> {
         unsafe {
-            match av_frame_get_best_effort_timestamp(self.as_ptr()) {
+            #[cfg(not(feature = "ffmpeg_5_0"))]
+            let timestamp = av_frame_get_best_effort_timestamp(self.as_ptr());
+            #[cfg(feature = "ffmpeg_5_0")]
+            let timestamp = (*self.as_ptr()).best_effort_timestamp;
+
+            match timestamp {
                 AV_NOPTS_VALUE => None,
                 t => Some(t as i64),
             }
@@ -140,13 +161,32 @@ impl Frame {
 
     #[inline]
     pub fn metadata(&self) -> DictionaryRef {
-        unsafe { DictionaryRef::wrap(av_frame_get_metadata(self.as_ptr())) }
+        unsafe {
+            #[cfg(not(feature = "ffmpeg_5_0"))]
+            let ptr = av_frame_get_metadata(self.as_ptr());
+            #[cfg(feature = "ffmpeg_5_0")]
+            let ptr = (*self.as_ptr()).metadata;
+
+            DictionaryRef::wrap(ptr)
+        }
+    }
+
+    /** Typed-parse the standard `creation_time`, `language`, and `rotate`
+     * keys out of this frame's metadata dictionary. */
+    #[inline]
+    pub fn media_metadata(&self) -> MediaMetadata {
+        MediaMetadata::parse(self.metadata())
     }
 
     #[inline]
     pub fn set_metadata(&mut self, value: Dictionary) {
         unsafe {
+            #[cfg(not(feature = "ffmpeg_5_0"))]
             av_frame_set_metadata(self.as_mut_ptr(), value.disown());
+            #[cfg(feature = "ffmpeg_5_0")]
+            {
+                (*self.as_mut_ptr()).metadata = value.disown();
+            }
         }
     }
 
@@ -185,6 +225,99 @@ impl Frame {
             av_frame_remove_side_data(self.as_mut_ptr(), kind.into());
         }
     }
+
+    /** Does this frame hold an opaque hardware surface (VAAPI, CUDA,
+     * VideoToolbox, ...) rather than directly readable pixel/sample
+     * data? */
+    #[inline]
+    pub fn is_hw_frame(&self) -> bool {
+        unsafe { !(*self.as_ptr()).hw_frames_ctx.is_null() }
+    }
+
+    /** Transfer frame data between a hardware and a software frame via
+     * `av_hwframe_transfer_data`: call it on a hardware frame to
+     * download its GPU surface into `dst`, a software frame, or on a
+     * software frame to upload into `dst`, a hardware frame. */
+    #[inline]
+    pub fn transfer_to(&self, dst: &mut Frame) -> Result<(), Error> {
+        unsafe {
+            match av_hwframe_transfer_data(dst.as_mut_ptr(), self.as_ptr(), 0) {
+                0 => Ok(()),
+                e => Err(Error::from(e)),
+            }
+        }
+    }
+
+    /** Enumerate every side-data entry FFmpeg has attached to this
+     * frame (motion vectors, HDR mastering display metadata, A/53
+     * captions, the display matrix, etc.), rather than probing for a
+     * single known `side_data::Type` at a time. */
+    #[inline]
+    pub fn side_data_all(&self) -> Vec<SideData> {
+        unsafe {
+            let ptr = self.as_ptr();
+            let len = (*ptr).nb_side_data as usize;
+
+            (0..len)
+                .map(|i| SideData::wrap(*(*ptr).side_data.add(i)))
+                .collect()
+        }
+    }
+
+    /** Deep-copy this frame's pixel/sample data and properties into a
+     * freshly allocated `Frame`. Unlike `clone()`, the result does not
+     * share buffers with `self` and can be written to independently. */
+    #[inline]
+    pub fn copy(&self) -> Result<Frame, Error> {
+        unsafe {
+            let src = self.as_ptr();
+            let mut frame = Frame::empty();
+            let dst = frame.as_mut_ptr();
+
+            (*dst).format = (*src).format;
+            (*dst).width = (*src).width;
+            (*dst).height = (*src).height;
+            (*dst).channel_layout = (*src).channel_layout;
+            (*dst).channels = (*src).channels;
+            (*dst).nb_samples = (*src).nb_samples;
+
+            match av_frame_get_buffer(dst, 0) {
+                e if e < 0 => return Err(Error::from(e)),
+                _ => {}
+            }
+
+            match av_frame_copy_props(dst, src) {
+                e if e < 0 => return Err(Error::from(e)),
+                _ => {}
+            }
+
+            match av_frame_copy(dst, src) {
+                e if e < 0 => return Err(Error::from(e)),
+                _ => {}
+            }
+
+            Ok(frame)
+        }
+    }
+}
+
+impl Clone for Frame {
+    /** A cheap, reference-counted clone: the new `Frame` shares the
+     * underlying `AVBufferRef`s with `self` via `av_frame_ref`, rather
+     * than copying pixel/sample data. Use `copy()` for an owned,
+     * independently writable buffer. */
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let ptr = av_frame_clone(self.as_ptr());
+
+            if ptr.is_null() {
+                panic!("av_frame_clone returned null (allocation failure)");
+            }
+
+            Frame { ptr, _own: true }
+        }
+    }
 }
 
 impl Drop for Frame {